@@ -0,0 +1,36 @@
+// ARC
+// `Rc` gives single-threaded shared ownership; `Arc` ("Atomic Reference Counted") is its
+// thread-safe sibling. Cloning an `Arc` gives another handle to the same heap allocation rather
+// than copying the data, so every clone can be moved into its own thread. See `myarc` for how
+// `Arc`'s reference counting is actually implemented under the hood.
+
+use std::sync::Arc;
+use std::thread;
+
+/// Shares one array across several threads via cloned `Arc` handles and sums what each thread
+/// reports back.
+pub fn demo() -> i32 {
+    let numbers = Arc::new([1, 2, 3, 4, 5]);
+
+    let handles: Vec<_> = (0..numbers.len())
+        .map(|i| {
+            let numbers = Arc::clone(&numbers);
+            thread::spawn(move || numbers[i])
+        })
+        .collect();
+
+    handles
+        .into_iter()
+        .map(|h| h.join().expect("worker thread panicked"))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_clone_sees_the_same_shared_data() {
+        assert_eq!(demo(), 1 + 2 + 3 + 4 + 5);
+    }
+}
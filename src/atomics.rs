@@ -0,0 +1,36 @@
+// ATOMICS
+// Atomics like `AtomicUsize` are the concurrent version of `Cell`: they let multiple threads
+// mutate a value without a lock, at the cost of only supporting certain platform-dependent types
+// (integers, `bool`, pointers). `fetch_add` is itself a single atomic read-modify-write.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+/// Has several threads race to increment a shared `AtomicUsize` and returns the final count.
+pub fn demo() -> usize {
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    thread::scope(|s| {
+        for _ in 0..8 {
+            let counter = Arc::clone(&counter);
+            s.spawn(move || {
+                for _ in 0..1_000 {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }
+            });
+        }
+    });
+
+    counter.load(Ordering::SeqCst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_increment_is_accounted_for() {
+        assert_eq!(demo(), 8 * 1_000);
+    }
+}
@@ -0,0 +1,138 @@
+// RWLOCK VS MUTEX CONTENTION BENCH
+// `mutex_rwlock` explains the theory - `RwLock` lets many readers in at once, `Mutex` serializes
+// everyone - but theory only shows up as a number under contention. This harness hammers a shared
+// `Vec<u64>` with reader and writer threads for a fixed duration and reports how many operations
+// each lock flavor got through, so the read-heavy tradeoff is something you can actually see.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+/// Reader/writer thread counts and how long to run them for.
+pub struct Workload {
+    pub readers: usize,
+    pub writers: usize,
+    pub duration: Duration,
+}
+
+/// Total operations a lock flavor completed while a [`Workload`] ran against it.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Report {
+    pub reads: u64,
+    pub writes: u64,
+}
+
+/// Runs `workload` against an `Arc`-free `RwLock<Vec<u64>>` and an equivalent `Mutex<Vec<u64>>`
+/// in turn (each on its own fresh data, so one doesn't warm the other up), returning
+/// `(rwlock_report, mutex_report)`.
+pub fn run(workload: &Workload) -> (Report, Report) {
+    (bench_rwlock(workload), bench_mutex(workload))
+}
+
+fn bench_rwlock(workload: &Workload) -> Report {
+    let data = RwLock::new(vec![0u64; 64]);
+    let stop = AtomicBool::new(false);
+    let read_counts: Vec<AtomicU64> = (0..workload.readers).map(|_| AtomicU64::new(0)).collect();
+    let write_counts: Vec<AtomicU64> = (0..workload.writers).map(|_| AtomicU64::new(0)).collect();
+
+    thread::scope(|s| {
+        for counter in &read_counts {
+            s.spawn(|| {
+                while !stop.load(Ordering::Relaxed) {
+                    let _ = data.read().unwrap().len();
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+        for counter in &write_counts {
+            s.spawn(|| {
+                let mut next = 0u64;
+                while !stop.load(Ordering::Relaxed) {
+                    let mut guard = data.write().unwrap();
+                    let len = guard.len();
+                    guard[next as usize % len] = next;
+                    drop(guard);
+                    next += 1;
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+
+        thread::sleep(workload.duration);
+        stop.store(true, Ordering::Relaxed);
+    });
+
+    Report {
+        reads: read_counts.iter().map(|c| c.load(Ordering::Relaxed)).sum(),
+        writes: write_counts
+            .iter()
+            .map(|c| c.load(Ordering::Relaxed))
+            .sum(),
+    }
+}
+
+fn bench_mutex(workload: &Workload) -> Report {
+    let data = Mutex::new(vec![0u64; 64]);
+    let stop = AtomicBool::new(false);
+    let read_counts: Vec<AtomicU64> = (0..workload.readers).map(|_| AtomicU64::new(0)).collect();
+    let write_counts: Vec<AtomicU64> = (0..workload.writers).map(|_| AtomicU64::new(0)).collect();
+
+    thread::scope(|s| {
+        for counter in &read_counts {
+            s.spawn(|| {
+                while !stop.load(Ordering::Relaxed) {
+                    let _ = data.lock().unwrap().len();
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+        for counter in &write_counts {
+            s.spawn(|| {
+                let mut next = 0u64;
+                while !stop.load(Ordering::Relaxed) {
+                    let mut guard = data.lock().unwrap();
+                    let len = guard.len();
+                    guard[next as usize % len] = next;
+                    drop(guard);
+                    next += 1;
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+
+        thread::sleep(workload.duration);
+        stop.store(true, Ordering::Relaxed);
+    });
+
+    Report {
+        reads: read_counts.iter().map(|c| c.load(Ordering::Relaxed)).sum(),
+        writes: write_counts
+            .iter()
+            .map(|c| c.load(Ordering::Relaxed))
+            .sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rwlock_outpaces_mutex_under_a_read_heavy_workload() {
+        let workload = Workload {
+            readers: 8,
+            writers: 1,
+            duration: Duration::from_millis(300),
+        };
+
+        let (rwlock_report, mutex_report) = run(&workload);
+
+        assert!(
+            rwlock_report.reads > mutex_report.reads,
+            "expected RwLock reads ({}) to outpace Mutex reads ({}) under a read-heavy workload",
+            rwlock_report.reads,
+            mutex_report.reads,
+        );
+    }
+}
@@ -0,0 +1,30 @@
+// THREAD BUILDER
+// `thread::spawn` is shorthand for `thread::Builder::new().spawn().unwrap()`. Going through
+// `Builder` directly lets us set things like the thread's name and stack size, and lets us
+// handle the `spawn` failure (hitting a resource limit, running out of memory) instead of
+// unwrapping it away.
+
+use std::thread;
+
+/// Spawns a named worker via `Builder` and returns the name it saw for itself.
+pub fn demo() -> String {
+    let builder = thread::Builder::new()
+        .name("worker-1".into())
+        .stack_size(4 * 1024 * 1024); // 4 MB
+
+    let handle = builder
+        .spawn(|| thread::current().name().unwrap_or("<unnamed>").to_string())
+        .expect("failed to spawn worker thread");
+
+    handle.join().expect("worker thread panicked")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn worker_sees_the_name_it_was_given() {
+        assert_eq!(demo(), "worker-1");
+    }
+}
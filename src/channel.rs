@@ -0,0 +1,136 @@
+// CHANNEL
+// Everything above shares state through `Arc`/`Mutex`/atomics. Message passing is the other core
+// concurrency model in Rust, so here's a bounded multi-producer, single-consumer queue: `send`
+// blocks while the queue is full, `recv` blocks while it's empty, and `recv` returns `None` once
+// every `Sender` has been dropped.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+
+struct State<T> {
+    queue: VecDeque<T>,
+    senders: usize,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    cap: usize,
+}
+
+/// Creates a bounded channel that holds at most `cap` items in flight.
+pub fn channel<T>(cap: usize) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::with_capacity(cap),
+            senders: 1,
+        }),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+        cap,
+    });
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+// SAFETY: all access to the shared queue goes through the `Mutex`, so handing `Sender`/`Receiver`
+// to another thread is sound whenever `T` itself is safe to send.
+unsafe impl<T: Send> Send for Sender<T> {}
+unsafe impl<T: Send> Send for Receiver<T> {}
+
+impl<T> Sender<T> {
+    /// Blocks while the queue is full, then pushes `item`.
+    pub fn send(&self, item: T) {
+        let mut state = self.shared.state.lock().unwrap();
+        while state.queue.len() == self.shared.cap {
+            state = self.shared.not_full.wait(state).unwrap();
+        }
+        state.queue.push_back(item);
+        self.shared.not_empty.notify_one();
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Sender<T> {
+        self.shared.state.lock().unwrap().senders += 1;
+        Sender {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        // Decrement and check under the same lock `recv` holds while testing its wait predicate,
+        // so a receiver can't observe "no senders left" as stale and park across this notify -
+        // that would be a lost wakeup, since nothing else would ever wake it.
+        let mut state = self.shared.state.lock().unwrap();
+        state.senders -= 1;
+        if state.senders == 0 {
+            self.shared.not_empty.notify_all();
+        }
+    }
+}
+
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    /// Blocks while the queue is empty, returning the next item, or `None` once every `Sender`
+    /// has been dropped and the queue has drained.
+    pub fn recv(&self) -> Option<T> {
+        let mut state = self.shared.state.lock().unwrap();
+        loop {
+            if let Some(item) = state.queue.pop_front() {
+                self.shared.not_full.notify_one();
+                return Some(item);
+            }
+            if state.senders == 0 {
+                return None;
+            }
+            state = self.shared.not_empty.wait(state).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::thread;
+
+    #[test]
+    fn every_item_is_received_exactly_once_and_receiver_terminates() {
+        let (tx, rx) = channel(4);
+        let producers = 4;
+        let items_per_producer = 1_000;
+
+        thread::scope(|s| {
+            for p in 0..producers {
+                let tx = tx.clone();
+                s.spawn(move || {
+                    for i in 0..items_per_producer {
+                        tx.send(p * items_per_producer + i);
+                    }
+                });
+            }
+            drop(tx);
+
+            let mut seen = HashSet::new();
+            while let Some(item) = rx.recv() {
+                assert!(seen.insert(item), "received {item} more than once");
+            }
+            assert_eq!(seen.len(), producers * items_per_producer);
+        });
+    }
+}
@@ -0,0 +1,30 @@
+// INTERIOR MUTABILITY
+// `Cell` and `RefCell` mutate data through a shared reference by moving the borrow-checking
+// rules from compile time to runtime (or, for `Cell`, by only ever exposing whole values via
+// `get`/`set` so there's no borrow to check at all). Both are single-threaded only - see `arc_demo`
+// and `mutex_rwlock` for the thread-safe equivalents.
+
+use std::cell::{Cell, RefCell};
+
+/// Exercises `Cell` (get/set on a `Copy` value) and `RefCell` (runtime-checked borrows),
+/// returning the two final values.
+pub fn demo() -> (i32, i32) {
+    let cell = Cell::new(1);
+    cell.set(cell.get() + 1);
+
+    let refcell = RefCell::new(1);
+    *refcell.borrow_mut() += 1;
+
+    let refcell_value = *refcell.borrow();
+    (cell.get(), refcell_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_containers_reflect_the_mutation() {
+        assert_eq!(demo(), (2, 2));
+    }
+}
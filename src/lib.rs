@@ -0,0 +1,21 @@
+//! Rust concurrency topics, made executable.
+//!
+//! Each module in the first group below started life as a standalone notes snippet; now every
+//! such topic is a deterministic `pub fn demo()` that can be called directly or run by name
+//! through the `demos` binary (`cargo run -- scoped`). The second group (`bench`, `channel`,
+//! `myarc`, `pool`, `spinlock`) holds larger building blocks with their own APIs and no `demo()`.
+
+pub mod arc_demo;
+pub mod atomics;
+pub mod builder;
+pub mod interior_mutability;
+pub mod mutex_rwlock;
+pub mod scoped;
+pub mod send_sync;
+pub mod threads;
+
+pub mod bench;
+pub mod channel;
+pub mod myarc;
+pub mod pool;
+pub mod spinlock;
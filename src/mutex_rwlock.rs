@@ -0,0 +1,36 @@
+// MUTEX AND RWLOCK
+// `Mutex<T>` allows only one borrow at a time, exclusive, full stop. `RwLock<T>` is the
+// concurrent version of `RefCell`: many readers *or* one writer. Both block (rather than panic,
+// like `RefCell` would) when a borrow can't be granted right away. See `bench` for when the
+// distinction between the two actually shows up in practice.
+
+use std::sync::{Mutex, RwLock};
+
+/// Writes through a `Mutex` a couple of times, then does the same through a `RwLock`, returning
+/// both final values.
+pub fn demo() -> (i32, i32) {
+    let counter = Mutex::new(0);
+    *counter.lock().unwrap() += 1;
+    *counter.lock().unwrap() += 1;
+
+    let value = RwLock::new(10);
+    {
+        let r = value.read().unwrap();
+        assert_eq!(*r, 10);
+    }
+    *value.write().unwrap() += 5;
+
+    let counter = *counter.lock().unwrap();
+    let value = *value.read().unwrap();
+    (counter, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_locks_reflect_their_writes() {
+        assert_eq!(demo(), (2, 15));
+    }
+}
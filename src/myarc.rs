@@ -0,0 +1,127 @@
+// MYARC
+// `Arc` and `AtomicUsize::fetch_add` show up separately above, but the notes never connect the
+// dots on how reference counting actually works under the hood. `MyArc<T>` below is a small,
+// educational reimplementation: a heap allocation holding a count next to the data, cloning bumps
+// the count, and the last `Drop` frees it.
+//
+// The ordering is the textbook pattern: incrementing on `clone` only needs to stop the count
+// itself from being corrupted (`Relaxed` is enough, since every thread already holds a valid
+// reference when it clones). Decrementing on `Drop` needs `Release` so earlier accesses to the
+// data can't be reordered past it; the thread that drops the *last* reference then issues an
+// `Acquire` fence so it's guaranteed to see every other thread's writes before it drops the data.
+
+use std::ops::Deref;
+use std::ptr::NonNull;
+use std::sync::atomic::{self, AtomicUsize, Ordering};
+
+struct ArcData<T> {
+    count: AtomicUsize,
+    data: T,
+}
+
+/// A minimal, educational `Arc<T>` built on `AtomicUsize` + a raw heap allocation.
+pub struct MyArc<T> {
+    ptr: NonNull<ArcData<T>>,
+}
+
+// SAFETY: `MyArc<T>` gives every clone shared access to the same `T`, so it can only be sent or
+// shared across threads when `T` itself is `Send + Sync` - exactly the bound `Arc<T>` uses.
+unsafe impl<T: Send + Sync> Send for MyArc<T> {}
+unsafe impl<T: Send + Sync> Sync for MyArc<T> {}
+
+impl<T> MyArc<T> {
+    pub fn new(data: T) -> MyArc<T> {
+        let boxed = Box::new(ArcData {
+            count: AtomicUsize::new(1),
+            data,
+        });
+        MyArc {
+            ptr: NonNull::from(Box::leak(boxed)),
+        }
+    }
+
+    fn data(&self) -> &ArcData<T> {
+        // SAFETY: as long as a `MyArc` exists, the `count` it contributed to keeps the
+        // allocation alive, so `ptr` is always valid to dereference.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T> Clone for MyArc<T> {
+    fn clone(&self) -> MyArc<T> {
+        // Relaxed: this thread already owns a reference, so there's nothing else it needs to
+        // synchronize with - it just needs the count itself not to be corrupted.
+        self.data().count.fetch_add(1, Ordering::Relaxed);
+        MyArc { ptr: self.ptr }
+    }
+}
+
+impl<T> Deref for MyArc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.data().data
+    }
+}
+
+impl<T> Drop for MyArc<T> {
+    fn drop(&mut self) {
+        // Release: makes sure nothing involving the data can be reordered after this decrement,
+        // so that if we were the last owner, the fence below really does see everything.
+        if self.data().count.fetch_sub(1, Ordering::Release) == 1 {
+            atomic::fence(Ordering::Acquire);
+            // SAFETY: the count just hit zero, and that can only happen once - we're the sole
+            // owner of the allocation and nobody else can be looking at it anymore.
+            unsafe {
+                drop(Box::from_raw(self.ptr.as_ptr()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize as Counter, Ordering as CounterOrdering};
+    use std::thread;
+
+    #[test]
+    fn clones_across_threads_see_the_same_data() {
+        let a = MyArc::new(42);
+        thread::scope(|s| {
+            for _ in 0..8 {
+                let a = a.clone();
+                s.spawn(move || {
+                    assert_eq!(*a, 42);
+                });
+            }
+        });
+    }
+
+    struct DropCounter<'a>(&'a Counter);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, CounterOrdering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn payload_is_dropped_exactly_once_after_last_owner_goes_away() {
+        let drops = Counter::new(0);
+        let a = MyArc::new(DropCounter(&drops));
+
+        thread::scope(|s| {
+            for _ in 0..4 {
+                let clone = a.clone();
+                s.spawn(move || {
+                    assert_eq!(clone.0.load(CounterOrdering::Relaxed), 0);
+                });
+            }
+        });
+
+        assert_eq!(drops.load(CounterOrdering::Relaxed), 0);
+        drop(a);
+        assert_eq!(drops.load(CounterOrdering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,331 @@
+// WORK-STEALING THREAD POOL
+// `thread::spawn`, `thread::scope`, `Arc` and `Mutex` are shown above in isolation, but a real
+// scheduler composes them: N worker threads, each owning a local queue of jobs, stealing from
+// each other when idle. This is the same idea Rayon is built on.
+//
+// Each worker treats its own queue as a stack (push/pop the back -> LIFO, good cache locality).
+// A worker with an empty queue becomes a thief: it picks another worker at random and steals from
+// the *front* of that worker's queue (FIFO from the victim's perspective, so the thief takes the
+// oldest - and usually biggest - piece of work).
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::env;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// A queued job, tagged with the [`ThreadPool::join`] call it belongs to (if any). `spawn`ed
+/// jobs carry no tag; `join`'s `b` closure does, so `join` can tell its own job apart from
+/// whatever another worker's `spawn` round-robined onto the same queue in the meantime.
+struct Entry {
+    tag: Option<u64>,
+    job: Job,
+}
+
+struct WorkerQueue {
+    deque: Mutex<VecDeque<Entry>>,
+    cvar: Condvar,
+}
+
+impl WorkerQueue {
+    fn new() -> Self {
+        WorkerQueue {
+            deque: Mutex::new(VecDeque::new()),
+            cvar: Condvar::new(),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct WorkerContext {
+    id: usize,
+    queues: Arc<Vec<Arc<WorkerQueue>>>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<WorkerContext>> = const { RefCell::new(None) };
+}
+
+/// Hands out a fresh tag to every [`ThreadPool::join`] call, process-wide, so its `b` entry can
+/// always be told apart from an unrelated `spawn`ed job on the same queue.
+static NEXT_JOIN_TAG: AtomicU64 = AtomicU64::new(0);
+
+/// A work-stealing thread pool.
+///
+/// Jobs submitted with [`ThreadPool::spawn`] land in the workers' queues round-robin; workers pull their
+/// own work first and steal from each other once they run dry.
+pub struct ThreadPool {
+    queues: Arc<Vec<Arc<WorkerQueue>>>,
+    handles: Vec<JoinHandle<()>>,
+    shutdown: Arc<AtomicBool>,
+    next: AtomicUsize,
+}
+
+impl ThreadPool {
+    /// Creates a pool with `n` workers. Passing `0` falls back to
+    /// [`thread::available_parallelism`], overridable with the `POOL_THREADS` env var.
+    pub fn new(n: usize) -> ThreadPool {
+        let n = if n > 0 {
+            n
+        } else {
+            env::var("POOL_THREADS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_else(|| thread::available_parallelism().map_or(1, |p| p.get()))
+        };
+
+        let queues: Arc<Vec<Arc<WorkerQueue>>> =
+            Arc::new((0..n).map(|_| Arc::new(WorkerQueue::new())).collect());
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let handles = (0..n)
+            .map(|id| {
+                let queues = queues.clone();
+                let shutdown = shutdown.clone();
+                thread::Builder::new()
+                    .name(format!("pool-worker-{id}"))
+                    .spawn(move || worker_loop(id, queues, shutdown))
+                    .expect("failed to spawn pool worker")
+            })
+            .collect();
+
+        ThreadPool {
+            queues,
+            handles,
+            shutdown,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Queues `job` for execution on the next worker (round-robin) and wakes it if parked.
+    pub fn spawn<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let i = self.next.fetch_add(1, Ordering::Relaxed) % self.queues.len();
+        push_and_notify(
+            &self.queues[i],
+            Entry {
+                tag: None,
+                job: Box::new(job),
+            },
+        );
+    }
+
+    /// Runs `a` on the calling thread while making `b` available for another worker to steal.
+    /// If nobody stole `b` by the time `a` finishes, it is run locally instead.
+    ///
+    /// Only meaningful when called from inside a job running on this pool; otherwise both
+    /// closures simply run, in order, on the calling thread.
+    pub fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+    where
+        A: FnOnce() -> RA,
+        B: FnOnce() -> RB + Send + 'static,
+        RB: Send + 'static,
+    {
+        let ctx = CURRENT.with(|c| c.borrow().clone());
+
+        let Some(ctx) = ctx else {
+            return (a(), b());
+        };
+
+        let result: Arc<Mutex<Option<RB>>> = Arc::new(Mutex::new(None));
+        let done = Arc::new((Mutex::new(false), Condvar::new()));
+        let tag = NEXT_JOIN_TAG.fetch_add(1, Ordering::Relaxed);
+
+        let job_b = {
+            let result = result.clone();
+            let done = done.clone();
+            Box::new(move || {
+                let r = b();
+                *result.lock().unwrap() = Some(r);
+                let (lock, cvar) = &*done;
+                *lock.lock().unwrap() = true;
+                cvar.notify_all();
+            }) as Job
+        };
+
+        let queue = &ctx.queues[ctx.id];
+        queue.deque.lock().unwrap().push_back(Entry {
+            tag: Some(tag),
+            job: job_b,
+        });
+
+        let ra = a();
+
+        // `spawn` round-robins across every worker's queue, including ours, so by the time `a()`
+        // returns, something else may have landed on top of `b` on our own deque. Look `b` up by
+        // its tag wherever it sits and take it out; only if it's gone from our deque has another
+        // worker stolen it, and then we wait for that worker to finish it.
+        let mut guard = queue.deque.lock().unwrap();
+        let pos = guard.iter().rposition(|entry| entry.tag == Some(tag));
+        let local = pos.and_then(|pos| guard.remove(pos)).map(|entry| entry.job);
+        drop(guard);
+
+        if let Some(job) = local {
+            job();
+        } else {
+            let (lock, cvar) = &*done;
+            let mut finished = lock.lock().unwrap();
+            while !*finished {
+                finished = cvar.wait(finished).unwrap();
+            }
+        }
+
+        let rb = result.lock().unwrap().take().expect("join partner never ran");
+        (ra, rb)
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Release);
+        for queue in self.queues.iter() {
+            queue.cvar.notify_all();
+        }
+        for handle in self.handles.drain(..) {
+            handle.join().unwrap();
+        }
+    }
+}
+
+fn push_and_notify(queue: &Arc<WorkerQueue>, entry: Entry) {
+    queue.deque.lock().unwrap().push_back(entry);
+    queue.cvar.notify_one();
+}
+
+fn worker_loop(id: usize, queues: Arc<Vec<Arc<WorkerQueue>>>, shutdown: Arc<AtomicBool>) {
+    CURRENT.with(|c| {
+        *c.borrow_mut() = Some(WorkerContext {
+            id,
+            queues: queues.clone(),
+        });
+    });
+
+    let my_queue = &queues[id];
+
+    loop {
+        // Bound to a `let` first rather than matched straight off the `.lock()` call: the
+        // `MutexGuard` temporary in an `if let` scrutinee lives for the whole arm, which would
+        // hold our own queue's lock while `job()` runs - and `ThreadPool::join` locks this same
+        // queue again from inside a job, deadlocking.
+        let next = my_queue.deque.lock().unwrap().pop_back();
+        if let Some(entry) = next {
+            (entry.job)();
+            continue;
+        }
+
+        if let Some(entry) = steal(id, &queues) {
+            (entry.job)();
+            continue;
+        }
+
+        let mut guard = my_queue.deque.lock().unwrap();
+        while guard.is_empty() && !shutdown.load(Ordering::Acquire) {
+            guard = my_queue.cvar.wait(guard).unwrap();
+        }
+        if shutdown.load(Ordering::Acquire) && guard.is_empty() {
+            return;
+        }
+    }
+}
+
+fn steal(id: usize, queues: &[Arc<WorkerQueue>]) -> Option<Entry> {
+    let n = queues.len();
+    if n <= 1 {
+        return None;
+    }
+    // Try every other worker once, starting from a random offset, rather than hammering one.
+    let start = pseudo_random(id) % n;
+    for offset in 0..n {
+        let victim = (start + offset) % n;
+        if victim == id {
+            continue;
+        }
+        if let Some(entry) = queues[victim].deque.lock().unwrap().pop_front() {
+            return Some(entry);
+        }
+    }
+    None
+}
+
+/// Cheap xorshift PRNG seeded from the worker id and the current time - no extra dependency
+/// needed just to pick a victim to steal from.
+fn pseudo_random(seed: usize) -> usize {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .subsec_nanos() as usize;
+    let mut x = seed ^ nanos ^ 0x9E3779B9;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn sums_a_large_slice_in_parallel() {
+        let data: Vec<u64> = (0..1_000_000u64).collect();
+        let serial: u64 = data.iter().sum();
+
+        let pool = ThreadPool::new(4);
+        let (tx, rx) = mpsc::channel();
+        let chunks: Vec<Vec<u64>> = data.chunks(10_000).map(|c| c.to_vec()).collect();
+
+        for chunk in chunks {
+            let tx = tx.clone();
+            pool.spawn(move || {
+                let partial: u64 = chunk.iter().sum();
+                tx.send(partial).unwrap();
+            });
+        }
+        drop(tx);
+
+        let parallel: u64 = rx.iter().sum();
+        assert_eq!(parallel, serial);
+    }
+
+    #[test]
+    fn join_runs_both_closures() {
+        let pool = ThreadPool::new(2);
+        pool.spawn(|| {
+            let (a, b) = ThreadPool::join(|| 1 + 1, || 2 + 2);
+            assert_eq!((a, b), (2, 4));
+        });
+    }
+
+    #[test]
+    fn join_tells_its_own_job_apart_from_a_spawn_on_the_same_queue() {
+        let pool = Arc::new(ThreadPool::new(2));
+        let (tx, rx) = mpsc::channel();
+
+        for _ in 0..200 {
+            let tx = tx.clone();
+            let outer_pool = pool.clone();
+            let inner_pool = pool.clone();
+            outer_pool.spawn(move || {
+                // Keep this worker's own queue busy with unrelated jobs while `a` runs below, so
+                // one of them has a chance to land on top of `b` before join looks for it again.
+                for _ in 0..4 {
+                    inner_pool.spawn(|| {});
+                }
+                let (a, b) = ThreadPool::join(|| 1 + 1, || 2 + 2);
+                tx.send((a, b)).unwrap();
+            });
+        }
+        drop(tx);
+
+        for (a, b) in rx.iter() {
+            assert_eq!((a, b), (2, 4));
+        }
+    }
+}
@@ -0,0 +1,36 @@
+// SCOPED THREADS
+// `thread::scope` lets spawned threads borrow from the parent's stack, because the scope
+// guarantees every thread it spawns is joined before the scope itself returns. The usual
+// borrowing rules still apply across the spawned threads: one mutable borrow, or many shared
+// borrows, never both at once.
+
+use std::thread;
+
+/// Scoped threads reading a shared slice: one computes the length, the other the sum. Both
+/// borrow `numbers` directly, with no `Arc` needed since the scope outlives them.
+pub fn demo() -> (usize, usize) {
+    let numbers = [1, 2, 3, 4, 5];
+
+    let mut len = 0;
+    let mut sum = 0;
+    thread::scope(|s| {
+        s.spawn(|| {
+            len = numbers.len();
+        });
+        s.spawn(|| {
+            sum = numbers.iter().sum();
+        });
+    });
+
+    (len, sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_length_and_sum() {
+        assert_eq!(demo(), (5, 15));
+    }
+}
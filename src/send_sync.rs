@@ -0,0 +1,46 @@
+// SEND AND SYNC
+// `Send` means T can be moved to another thread; `Sync` means &T can be shared between threads.
+// Both are usually auto-implemented, but raw pointers are neither, so a type holding one has to
+// opt in explicitly with an `unsafe impl`. `unsafe` here means the compiler can no longer check
+// this for us - we're promising it ourselves.
+
+use std::thread;
+
+struct X {
+    p: *mut i32,
+}
+
+// SAFETY: `X` doesn't alias its pointer anywhere else, so handing ownership of it to another
+// thread is just like handing ownership of the `i32` it points at.
+unsafe impl Send for X {}
+
+/// Moves a raw pointer to another thread inside a type that opted in to `Send`, mutates through
+/// it there, and reads the result back on the main thread.
+pub fn demo() -> i32 {
+    let ptr = Box::into_raw(Box::new(41));
+    let x = X { p: ptr };
+
+    let x = thread::spawn(move || {
+        // SAFETY: `ptr` was just allocated above and nothing else accesses it concurrently.
+        unsafe { *x.p += 1 };
+        x
+    })
+    .join()
+    .expect("worker thread panicked");
+
+    // SAFETY: the worker thread finished before `join` returned, so this is the only access.
+    let result = unsafe { *x.p };
+    // SAFETY: reconstructing the `Box` we leaked into the raw pointer frees it exactly once.
+    unsafe { drop(Box::from_raw(x.p)) };
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutation_on_worker_thread_is_observed_after_join() {
+        assert_eq!(demo(), 42);
+    }
+}
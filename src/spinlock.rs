@@ -0,0 +1,97 @@
+// SPINLOCK
+// `UnsafeCell` is the primitive that `Cell`, `RefCell` and the standard locks in `mutex_rwlock`
+// are all built on. Here's a lock built directly on it from scratch: a
+// spinlock, the simplest mutual-exclusion primitive there is - instead of parking the thread like
+// `Mutex` does, a locked `SpinLock` just busy-loops until it sees the lock released.
+
+use std::cell::UnsafeCell;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A minimal spinlock around `T`, built on `UnsafeCell` + `AtomicBool`.
+pub struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: `SpinLock` only ever hands out access to its `T` through a `SpinGuard`, and the
+// `locked` flag guarantees at most one guard exists at a time - so sharing a `&SpinLock<T>`
+// across threads is sound as long as `T: Send`, exactly like `Mutex<T>`.
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+impl<T> SpinLock<T> {
+    pub fn new(value: T) -> SpinLock<T> {
+        SpinLock {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Spins until the lock is acquired, then returns a guard that releases it on drop.
+    pub fn lock(&self) -> SpinGuard<'_, T> {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            std::hint::spin_loop();
+        }
+        SpinGuard { lock: self }
+    }
+}
+
+/// RAII guard returned by [`SpinLock::lock`]. Dereferences to `T`; releases the lock on drop.
+pub struct SpinGuard<'a, T> {
+    lock: &'a SpinLock<T>,
+}
+
+impl<T> Deref for SpinGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: only one `SpinGuard` can exist while `locked` is true, so this reference is
+        // exclusive for as long as the guard lives.
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for SpinGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: same as `Deref` above, and `&mut self` proves nobody else is reading through
+        // this guard right now either.
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for SpinGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.locked.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn many_threads_increment_without_losing_updates() {
+        let counter = Arc::new(SpinLock::new(0i32));
+        let threads = 8;
+        let increments_per_thread = 10_000;
+
+        thread::scope(|s| {
+            for _ in 0..threads {
+                let counter = Arc::clone(&counter);
+                s.spawn(move || {
+                    for _ in 0..increments_per_thread {
+                        *counter.lock() += 1;
+                    }
+                });
+            }
+        });
+
+        assert_eq!(*counter.lock(), threads * increments_per_thread);
+    }
+}
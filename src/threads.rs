@@ -0,0 +1,30 @@
+// THREADS
+// `thread::spawn` lets us run tasks concurrently. The handle it returns should be joined,
+// otherwise the main thread might finish (and the process exit) before the spawned ones do.
+
+use std::thread;
+
+/// Spawns a few worker threads, joins every handle, and returns their ids summed together -
+/// deterministic because it depends only on which workers ran, not on timing.
+pub fn demo() -> usize {
+    let handles: Vec<_> = (0..4).map(|i| thread::spawn(move || worker(i))).collect();
+
+    handles
+        .into_iter()
+        .map(|h| h.join().expect("worker thread panicked"))
+        .sum()
+}
+
+fn worker(id: usize) -> usize {
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_worker_ids() {
+        assert_eq!(demo(), (0..4).sum::<usize>());
+    }
+}